@@ -5,13 +5,19 @@
 
 extern crate alloc;
 
-use alloc::rc::Rc;
-use alloc::sync::Arc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak as RcWeak};
+use alloc::sync::{Arc, Weak as ArcWeak};
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
-use core::ops::Deref;
+use core::iter::FromIterator;
+use core::ops::{Bound, Deref, RangeBounds};
 
 /// A read-only view into part of an underlying reference-counted slice.
 ///
@@ -59,6 +65,24 @@ impl<T> From<Rc<[T]>> for RcSlice<T> {
     }
 }
 
+impl<T> From<Vec<T>> for RcSlice<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self::from(Rc::<[T]>::from(v))
+    }
+}
+
+impl<T> From<Box<[T]>> for RcSlice<T> {
+    fn from(b: Box<[T]>) -> Self {
+        Self::from(Rc::<[T]>::from(b))
+    }
+}
+
+impl<T> FromIterator<T> for RcSlice<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Rc::<[T]>::from_iter(iter))
+    }
+}
+
 impl<T: fmt::Debug> fmt::Debug for RcSlice<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.deref().fmt(f)
@@ -98,6 +122,23 @@ impl<T: Hash> Hash for RcSlice<T> {
 }
 
 impl<T> RcSlice<T> {
+    /// Returns a new view over a fresh allocation containing a clone of every element of `s`.
+    pub fn from_slice(s: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from(Rc::<[T]>::from(s))
+    }
+
+    /// Returns a zero-length view over an empty allocation.
+    pub fn empty() -> Self {
+        Self {
+            underlying: Rc::from([]),
+            start: 0,
+            end: 0,
+        }
+    }
+
     /// Returns the starting and ending indices of the view `it` within the underlying slice.
     pub fn bounds(it: &Self) -> (usize, usize) {
         (it.start, it.end)
@@ -176,6 +217,299 @@ impl<T> RcSlice<T> {
             None
         }
     }
+
+    /// Returns a weak view over the same range as `it`, which does not keep the underlying
+    /// allocation alive.
+    pub fn downgrade(it: &Self) -> WeakRcSlice<T> {
+        WeakRcSlice {
+            underlying: Rc::downgrade(&it.underlying),
+            start: it.start,
+            end: it.end,
+        }
+    }
+
+    /// Returns a new view of `it` narrowed to `range`, where `range` is interpreted relative to
+    /// the current view rather than the underlying allocation.
+    ///
+    /// Returns `None` if `range` starts after it ends, or if it extends past the end of `it`.
+    pub fn subslice(it: &Self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let len = it.end - it.start;
+
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1)?,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+
+        if start > end || end > len {
+            return None;
+        }
+
+        Some(Self {
+            underlying: it.underlying.clone(),
+            start: it.start + start,
+            end: it.start + end,
+        })
+    }
+
+    /// Returns a mutable reference to the elements in view, if `it` is the only `RcSlice` (and
+    /// there are no `WeakRcSlice`s) pointing at the underlying allocation.
+    ///
+    /// Returns `None` otherwise, leaving `it` unchanged.
+    pub fn get_mut(it: &mut Self) -> Option<&mut [T]> {
+        let (start, end) = (it.start, it.end);
+
+        Rc::get_mut(&mut it.underlying).map(|underlying| &mut underlying[start..end])
+    }
+
+    /// Returns a mutable reference to the elements in view, cloning the underlying allocation
+    /// first if it is shared with other `RcSlice`s, or if any `WeakRcSlice` still points at it.
+    ///
+    /// The clone contains only the elements currently in view, so this also compacts away any
+    /// unused prefix or suffix of the previous allocation.
+    pub fn make_mut(it: &mut Self) -> &mut [T]
+    where
+        T: Clone,
+    {
+        if Rc::get_mut(&mut it.underlying).is_none() {
+            let compacted: Rc<[T]> = it.underlying[it.start..it.end].to_vec().into();
+            it.underlying = compacted;
+            it.start = 0;
+            it.end = it.underlying.len();
+        }
+
+        let (start, end) = (it.start, it.end);
+        let underlying = Rc::get_mut(&mut it.underlying).expect("just ensured unique ownership");
+
+        &mut underlying[start..end]
+    }
+
+    /// Returns an iterator over `size`-element chunks of `it`, each an owned view sharing the
+    /// same backing allocation. The last chunk may have fewer than `size` elements.
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks(it: &Self, size: usize) -> RcChunks<T> {
+        assert_ne!(size, 0, "chunk size must be non-zero");
+
+        RcChunks {
+            underlying: it.underlying.clone(),
+            cursor: it.start,
+            end: it.end,
+            size,
+        }
+    }
+
+    /// Returns an iterator over overlapping `size`-element windows of `it`, each an owned view
+    /// sharing the same backing allocation.
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows(it: &Self, size: usize) -> RcWindows<T> {
+        assert_ne!(size, 0, "window size must be non-zero");
+
+        RcWindows {
+            underlying: it.underlying.clone(),
+            cursor: it.start,
+            end: it.end,
+            size,
+        }
+    }
+
+    /// Returns an iterator over the segments of `it` separated by elements matching `pred`, each
+    /// an owned view sharing the same backing allocation. The delimiter elements themselves are
+    /// consumed and not included in any segment.
+    pub fn split<P>(it: &Self, pred: P) -> RcSplit<T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        RcSplit {
+            underlying: it.underlying.clone(),
+            cursor: it.start,
+            end: it.end,
+            pred,
+            done: false,
+        }
+    }
+}
+
+/// An iterator over non-overlapping chunks of an `RcSlice`, created by [`RcSlice::chunks`].
+pub struct RcChunks<T> {
+    underlying: Rc<[T]>,
+    cursor: usize,
+    end: usize,
+    size: usize,
+}
+
+impl<T> Iterator for RcChunks<T> {
+    type Item = RcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor >= self.end {
+            return None;
+        }
+
+        let start = self.cursor;
+        let stop = start + (self.end - start).min(self.size);
+        self.cursor = stop;
+
+        Some(RcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+impl<T> DoubleEndedIterator for RcChunks<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.cursor >= self.end {
+            return None;
+        }
+
+        let remainder = (self.end - self.cursor) % self.size;
+        let size = if remainder == 0 { self.size } else { remainder };
+        let start = self.end - size;
+        let stop = self.end;
+        self.end = start;
+
+        Some(RcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+/// An iterator over overlapping windows of an `RcSlice`, created by [`RcSlice::windows`].
+pub struct RcWindows<T> {
+    underlying: Rc<[T]>,
+    cursor: usize,
+    end: usize,
+    size: usize,
+}
+
+impl<T> Iterator for RcWindows<T> {
+    type Item = RcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end - self.cursor < self.size {
+            return None;
+        }
+
+        let start = self.cursor;
+        let stop = start + self.size;
+        self.cursor += 1;
+
+        Some(RcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+impl<T> DoubleEndedIterator for RcWindows<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.end - self.cursor < self.size {
+            return None;
+        }
+
+        let stop = self.end;
+        let start = stop - self.size;
+        self.end -= 1;
+
+        Some(RcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+/// An iterator over the segments of an `RcSlice` separated by a predicate, created by
+/// [`RcSlice::split`].
+pub struct RcSplit<T, P> {
+    underlying: Rc<[T]>,
+    cursor: usize,
+    end: usize,
+    pred: P,
+    done: bool,
+}
+
+impl<T, P> Iterator for RcSplit<T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = RcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = self.cursor;
+        let mut cursor = start;
+
+        while cursor < self.end {
+            if (self.pred)(&self.underlying[cursor]) {
+                self.cursor = cursor + 1;
+
+                return Some(RcSlice {
+                    underlying: self.underlying.clone(),
+                    start,
+                    end: cursor,
+                });
+            }
+
+            cursor += 1;
+        }
+
+        self.done = true;
+
+        Some(RcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: self.end,
+        })
+    }
+}
+
+/// A weak view into part of an underlying reference-counted slice, created by
+/// [`RcSlice::downgrade`].
+///
+/// Unlike `RcSlice`, holding a `WeakRcSlice` does not keep the backing allocation alive.
+pub struct WeakRcSlice<T> {
+    underlying: RcWeak<[T]>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> Clone for WeakRcSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            underlying: self.underlying.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<T> WeakRcSlice<T> {
+    /// Attempts to upgrade `it` to a strong `RcSlice`, returning `None` if the underlying
+    /// allocation has already been dropped.
+    pub fn upgrade(it: &Self) -> Option<RcSlice<T>> {
+        let underlying = RcWeak::upgrade(&it.underlying)?;
+
+        Some(RcSlice {
+            underlying,
+            start: it.start,
+            end: it.end,
+        })
+    }
 }
 
 /// A read-only view into part of an underlying atomically reference-counted slice.
@@ -224,6 +558,24 @@ impl<T> From<Arc<[T]>> for ArcSlice<T> {
     }
 }
 
+impl<T> From<Vec<T>> for ArcSlice<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self::from(Arc::<[T]>::from(v))
+    }
+}
+
+impl<T> From<Box<[T]>> for ArcSlice<T> {
+    fn from(b: Box<[T]>) -> Self {
+        Self::from(Arc::<[T]>::from(b))
+    }
+}
+
+impl<T> FromIterator<T> for ArcSlice<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Arc::<[T]>::from_iter(iter))
+    }
+}
+
 impl<T: fmt::Debug> fmt::Debug for ArcSlice<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.deref().fmt(f)
@@ -263,6 +615,23 @@ impl<T: Hash> Hash for ArcSlice<T> {
 }
 
 impl<T> ArcSlice<T> {
+    /// Returns a new view over a fresh allocation containing a clone of every element of `s`.
+    pub fn from_slice(s: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from(Arc::<[T]>::from(s))
+    }
+
+    /// Returns a zero-length view over an empty allocation.
+    pub fn empty() -> Self {
+        Self {
+            underlying: Arc::from([]),
+            start: 0,
+            end: 0,
+        }
+    }
+
     /// Returns the starting and ending indices of the view `it` within the underlying slice.
     pub fn bounds(it: &Self) -> (usize, usize) {
         (it.start, it.end)
@@ -341,8 +710,745 @@ impl<T> ArcSlice<T> {
             None
         }
     }
+
+    /// Returns a weak view over the same range as `it`, which does not keep the underlying
+    /// allocation alive.
+    pub fn downgrade(it: &Self) -> WeakArcSlice<T> {
+        WeakArcSlice {
+            underlying: Arc::downgrade(&it.underlying),
+            start: it.start,
+            end: it.end,
+        }
+    }
+
+    /// Returns a new view of `it` narrowed to `range`, where `range` is interpreted relative to
+    /// the current view rather than the underlying allocation.
+    ///
+    /// Returns `None` if `range` starts after it ends, or if it extends past the end of `it`.
+    pub fn subslice(it: &Self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let len = it.end - it.start;
+
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1)?,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+
+        if start > end || end > len {
+            return None;
+        }
+
+        Some(Self {
+            underlying: it.underlying.clone(),
+            start: it.start + start,
+            end: it.start + end,
+        })
+    }
+
+    /// Returns a mutable reference to the elements in view, if `it` is the only `ArcSlice` (and
+    /// there are no `WeakArcSlice`s) pointing at the underlying allocation.
+    ///
+    /// Returns `None` otherwise, leaving `it` unchanged.
+    pub fn get_mut(it: &mut Self) -> Option<&mut [T]> {
+        let (start, end) = (it.start, it.end);
+
+        Arc::get_mut(&mut it.underlying).map(|underlying| &mut underlying[start..end])
+    }
+
+    /// Returns a mutable reference to the elements in view, cloning the underlying allocation
+    /// first if it is shared with other `ArcSlice`s, or if any `WeakArcSlice` still points at it.
+    ///
+    /// The clone contains only the elements currently in view, so this also compacts away any
+    /// unused prefix or suffix of the previous allocation.
+    pub fn make_mut(it: &mut Self) -> &mut [T]
+    where
+        T: Clone,
+    {
+        if Arc::get_mut(&mut it.underlying).is_none() {
+            let compacted: Arc<[T]> = it.underlying[it.start..it.end].to_vec().into();
+            it.underlying = compacted;
+            it.start = 0;
+            it.end = it.underlying.len();
+        }
+
+        let (start, end) = (it.start, it.end);
+        let underlying = Arc::get_mut(&mut it.underlying).expect("just ensured unique ownership");
+
+        &mut underlying[start..end]
+    }
+
+    /// Returns an iterator over `size`-element chunks of `it`, each an owned view sharing the
+    /// same backing allocation. The last chunk may have fewer than `size` elements.
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks(it: &Self, size: usize) -> ArcChunks<T> {
+        assert_ne!(size, 0, "chunk size must be non-zero");
+
+        ArcChunks {
+            underlying: it.underlying.clone(),
+            cursor: it.start,
+            end: it.end,
+            size,
+        }
+    }
+
+    /// Returns an iterator over overlapping `size`-element windows of `it`, each an owned view
+    /// sharing the same backing allocation.
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows(it: &Self, size: usize) -> ArcWindows<T> {
+        assert_ne!(size, 0, "window size must be non-zero");
+
+        ArcWindows {
+            underlying: it.underlying.clone(),
+            cursor: it.start,
+            end: it.end,
+            size,
+        }
+    }
+
+    /// Returns an iterator over the segments of `it` separated by elements matching `pred`, each
+    /// an owned view sharing the same backing allocation. The delimiter elements themselves are
+    /// consumed and not included in any segment.
+    pub fn split<P>(it: &Self, pred: P) -> ArcSplit<T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        ArcSplit {
+            underlying: it.underlying.clone(),
+            cursor: it.start,
+            end: it.end,
+            pred,
+            done: false,
+        }
+    }
+}
+
+/// An iterator over non-overlapping chunks of an `ArcSlice`, created by [`ArcSlice::chunks`].
+pub struct ArcChunks<T> {
+    underlying: Arc<[T]>,
+    cursor: usize,
+    end: usize,
+    size: usize,
+}
+
+impl<T> Iterator for ArcChunks<T> {
+    type Item = ArcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor >= self.end {
+            return None;
+        }
+
+        let start = self.cursor;
+        let stop = start + (self.end - start).min(self.size);
+        self.cursor = stop;
+
+        Some(ArcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+impl<T> DoubleEndedIterator for ArcChunks<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.cursor >= self.end {
+            return None;
+        }
+
+        let remainder = (self.end - self.cursor) % self.size;
+        let size = if remainder == 0 { self.size } else { remainder };
+        let start = self.end - size;
+        let stop = self.end;
+        self.end = start;
+
+        Some(ArcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+/// An iterator over overlapping windows of an `ArcSlice`, created by [`ArcSlice::windows`].
+pub struct ArcWindows<T> {
+    underlying: Arc<[T]>,
+    cursor: usize,
+    end: usize,
+    size: usize,
+}
+
+impl<T> Iterator for ArcWindows<T> {
+    type Item = ArcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end - self.cursor < self.size {
+            return None;
+        }
+
+        let start = self.cursor;
+        let stop = start + self.size;
+        self.cursor += 1;
+
+        Some(ArcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+impl<T> DoubleEndedIterator for ArcWindows<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.end - self.cursor < self.size {
+            return None;
+        }
+
+        let stop = self.end;
+        let start = stop - self.size;
+        self.end -= 1;
+
+        Some(ArcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: stop,
+        })
+    }
+}
+
+/// An iterator over the segments of an `ArcSlice` separated by a predicate, created by
+/// [`ArcSlice::split`].
+pub struct ArcSplit<T, P> {
+    underlying: Arc<[T]>,
+    cursor: usize,
+    end: usize,
+    pred: P,
+    done: bool,
+}
+
+impl<T, P> Iterator for ArcSplit<T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = ArcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = self.cursor;
+        let mut cursor = start;
+
+        while cursor < self.end {
+            if (self.pred)(&self.underlying[cursor]) {
+                self.cursor = cursor + 1;
+
+                return Some(ArcSlice {
+                    underlying: self.underlying.clone(),
+                    start,
+                    end: cursor,
+                });
+            }
+
+            cursor += 1;
+        }
+
+        self.done = true;
+
+        Some(ArcSlice {
+            underlying: self.underlying.clone(),
+            start,
+            end: self.end,
+        })
+    }
+}
+
+/// A weak view into part of an underlying atomically reference-counted slice, created by
+/// [`ArcSlice::downgrade`].
+///
+/// Unlike `ArcSlice`, holding a `WeakArcSlice` does not keep the backing allocation alive.
+pub struct WeakArcSlice<T> {
+    underlying: ArcWeak<[T]>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> Clone for WeakArcSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            underlying: self.underlying.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<T> WeakArcSlice<T> {
+    /// Attempts to upgrade `it` to a strong `ArcSlice`, returning `None` if the underlying
+    /// allocation has already been dropped.
+    pub fn upgrade(it: &Self) -> Option<ArcSlice<T>> {
+        let underlying = ArcWeak::upgrade(&it.underlying)?;
+
+        Some(ArcSlice {
+            underlying,
+            start: it.start,
+            end: it.end,
+        })
+    }
 }
 
 pub type RcBytes = RcSlice<u8>;
 
 pub type ArcBytes = ArcSlice<u8>;
+
+/// `Read`/`BufRead` cursor semantics for [`RcBytes`]/[`ArcBytes`], gated behind the `std`
+/// feature.
+///
+/// These treat the view as a consuming cursor over the shared, read-only backing buffer:
+/// `read` copies bytes from the front of the window and advances `start`, `fill_buf` exposes the
+/// rest of the window, and `consume` advances `start`. Because the buffer is refcount-shared,
+/// many independent cursors can drain the same data at different rates without copying it.
+#[cfg(feature = "std")]
+mod io_impls {
+    use super::{ArcBytes, ArcSlice, RcBytes, RcSlice};
+    use std::io::{BufRead, Read, Result};
+
+    impl Read for RcBytes {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let n = buf.len().min(self.as_ref().len());
+            buf[..n].copy_from_slice(&self.as_ref()[..n]);
+            RcSlice::advance(self, n);
+
+            Ok(n)
+        }
+    }
+
+    impl BufRead for RcBytes {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            let (start, end) = RcSlice::bounds(self);
+
+            Ok(&self.underlying[start..end])
+        }
+
+        fn consume(&mut self, amt: usize) {
+            RcSlice::advance(self, amt);
+        }
+    }
+
+    impl Read for ArcBytes {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let n = buf.len().min(self.as_ref().len());
+            buf[..n].copy_from_slice(&self.as_ref()[..n]);
+            ArcSlice::advance(self, n);
+
+            Ok(n)
+        }
+    }
+
+    impl BufRead for ArcBytes {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            let (start, end) = ArcSlice::bounds(self);
+
+            Ok(&self.underlying[start..end])
+        }
+
+        fn consume(&mut self, amt: usize) {
+            ArcSlice::advance(self, amt);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rc_weak_upgrade_fails_after_last_strong_dropped() {
+        let strong: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+        let weak = RcSlice::downgrade(&strong);
+
+        assert!(WeakRcSlice::upgrade(&weak).is_some());
+
+        drop(strong);
+
+        assert!(WeakRcSlice::upgrade(&weak).is_none());
+    }
+
+    #[test]
+    fn arc_weak_upgrade_fails_after_last_strong_dropped() {
+        let strong: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+        let weak = ArcSlice::downgrade(&strong);
+
+        assert!(WeakArcSlice::upgrade(&weak).is_some());
+
+        drop(strong);
+
+        assert!(WeakArcSlice::upgrade(&weak).is_none());
+    }
+
+    #[test]
+    fn rc_chunks_meet_in_the_middle() {
+        let s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3, 4, 5]));
+        let mut chunks = RcSlice::chunks(&s, 2);
+
+        assert_eq!(&*chunks.next().unwrap(), [1, 2]);
+        assert_eq!(&*chunks.next_back().unwrap(), [5]);
+        assert_eq!(&*chunks.next().unwrap(), [3, 4]);
+        assert!(chunks.next().is_none());
+        assert!(chunks.next_back().is_none());
+    }
+
+    #[test]
+    fn arc_chunks_meet_in_the_middle() {
+        let s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3, 4, 5]));
+        let mut chunks = ArcSlice::chunks(&s, 2);
+
+        assert_eq!(&*chunks.next().unwrap(), [1, 2]);
+        assert_eq!(&*chunks.next_back().unwrap(), [5]);
+        assert_eq!(&*chunks.next().unwrap(), [3, 4]);
+        assert!(chunks.next().is_none());
+        assert!(chunks.next_back().is_none());
+    }
+
+    #[test]
+    fn rc_chunks_does_not_overflow_on_huge_size_past_a_non_zero_start() {
+        let backing: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3, 4, 5]));
+        let mut view = backing.clone();
+        RcSlice::advance(&mut view, 2).unwrap();
+
+        let mut chunks = RcSlice::chunks(&view, usize::MAX - 1);
+
+        assert_eq!(&*chunks.next().unwrap(), [3, 4, 5]);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn arc_chunks_does_not_overflow_on_huge_size_past_a_non_zero_start() {
+        let backing: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3, 4, 5]));
+        let mut view = backing.clone();
+        ArcSlice::advance(&mut view, 2).unwrap();
+
+        let mut chunks = ArcSlice::chunks(&view, usize::MAX - 1);
+
+        assert_eq!(&*chunks.next().unwrap(), [3, 4, 5]);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn rc_windows_meet_in_the_middle() {
+        let s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3, 4]));
+        let mut windows = RcSlice::windows(&s, 2);
+
+        assert_eq!(&*windows.next().unwrap(), [1, 2]);
+        assert_eq!(&*windows.next_back().unwrap(), [3, 4]);
+        assert_eq!(&*windows.next().unwrap(), [2, 3]);
+        assert!(windows.next().is_none());
+        assert!(windows.next_back().is_none());
+    }
+
+    #[test]
+    fn arc_windows_meet_in_the_middle() {
+        let s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3, 4]));
+        let mut windows = ArcSlice::windows(&s, 2);
+
+        assert_eq!(&*windows.next().unwrap(), [1, 2]);
+        assert_eq!(&*windows.next_back().unwrap(), [3, 4]);
+        assert_eq!(&*windows.next().unwrap(), [2, 3]);
+        assert!(windows.next().is_none());
+        assert!(windows.next_back().is_none());
+    }
+
+    #[test]
+    fn rc_windows_does_not_overflow_when_size_exceeds_view() {
+        let backing: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3, 4, 5]));
+        let mut view = backing.clone();
+        RcSlice::advance(&mut view, 2).unwrap();
+
+        let mut windows = RcSlice::windows(&view, usize::MAX - 1);
+
+        assert!(windows.next().is_none());
+        assert!(windows.next_back().is_none());
+    }
+
+    #[test]
+    fn arc_windows_does_not_overflow_when_size_exceeds_view() {
+        let backing: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3, 4, 5]));
+        let mut view = backing.clone();
+        ArcSlice::advance(&mut view, 2).unwrap();
+
+        let mut windows = ArcSlice::windows(&view, usize::MAX - 1);
+
+        assert!(windows.next().is_none());
+        assert!(windows.next_back().is_none());
+    }
+
+    #[test]
+    fn rc_split_yields_trailing_empty_segment() {
+        let s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+        let mut segments = RcSlice::split(&s, |&x| x == 3);
+
+        assert_eq!(&*segments.next().unwrap(), [1, 2]);
+        assert_eq!(&*segments.next().unwrap(), []);
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn arc_split_yields_trailing_empty_segment() {
+        let s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+        let mut segments = ArcSlice::split(&s, |&x| x == 3);
+
+        assert_eq!(&*segments.next().unwrap(), [1, 2]);
+        assert_eq!(&*segments.next().unwrap(), []);
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn rc_subslice_unbounded_returns_full_view() {
+        let s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+        let sub = RcSlice::subslice(&s, ..).unwrap();
+
+        assert_eq!(&*sub, [1, 2, 3]);
+    }
+
+    #[test]
+    fn rc_subslice_out_of_range_end_returns_none() {
+        let s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+
+        assert!(RcSlice::subslice(&s, 0..4).is_none());
+    }
+
+    #[test]
+    fn rc_subslice_reversed_range_returns_none() {
+        let s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+
+        assert!(RcSlice::subslice(&s, (Bound::Included(2), Bound::Excluded(1))).is_none());
+    }
+
+    #[test]
+    fn rc_subslice_excluded_max_bound_does_not_panic() {
+        let s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+
+        assert!(RcSlice::subslice(&s, (Bound::Excluded(usize::MAX), Bound::Unbounded)).is_none());
+    }
+
+    #[test]
+    fn arc_subslice_unbounded_returns_full_view() {
+        let s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+        let sub = ArcSlice::subslice(&s, ..).unwrap();
+
+        assert_eq!(&*sub, [1, 2, 3]);
+    }
+
+    #[test]
+    fn arc_subslice_out_of_range_end_returns_none() {
+        let s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+
+        assert!(ArcSlice::subslice(&s, 0..4).is_none());
+    }
+
+    #[test]
+    fn arc_subslice_reversed_range_returns_none() {
+        let s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+
+        assert!(ArcSlice::subslice(&s, (Bound::Included(2), Bound::Excluded(1))).is_none());
+    }
+
+    #[test]
+    fn arc_subslice_excluded_max_bound_does_not_panic() {
+        let s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+
+        assert!(ArcSlice::subslice(&s, (Bound::Excluded(usize::MAX), Bound::Unbounded)).is_none());
+    }
+
+    #[test]
+    fn rc_make_mut_clones_when_weak_ref_exists() {
+        let mut s: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+        let weak = RcSlice::downgrade(&s);
+
+        assert!(WeakRcSlice::upgrade(&weak).is_some());
+
+        RcSlice::make_mut(&mut s)[0] = 9;
+
+        assert!(WeakRcSlice::upgrade(&weak).is_none());
+        assert_eq!(&*s, [9, 2, 3]);
+    }
+
+    #[test]
+    fn rc_make_mut_does_not_mutate_other_shared_holder() {
+        let mut a: RcSlice<i32> = RcSlice::from(Rc::from([1, 2, 3]));
+        let b = a.clone();
+
+        RcSlice::make_mut(&mut a)[0] = 9;
+
+        assert_eq!(&*a, [9, 2, 3]);
+        assert_eq!(&*b, [1, 2, 3]);
+    }
+
+    #[test]
+    fn arc_make_mut_clones_when_weak_ref_exists() {
+        let mut s: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+        let weak = ArcSlice::downgrade(&s);
+
+        assert!(WeakArcSlice::upgrade(&weak).is_some());
+
+        ArcSlice::make_mut(&mut s)[0] = 9;
+
+        assert!(WeakArcSlice::upgrade(&weak).is_none());
+        assert_eq!(&*s, [9, 2, 3]);
+    }
+
+    #[test]
+    fn arc_make_mut_does_not_mutate_other_shared_holder() {
+        let mut a: ArcSlice<i32> = ArcSlice::from(Arc::from([1, 2, 3]));
+        let b = a.clone();
+
+        ArcSlice::make_mut(&mut a)[0] = 9;
+
+        assert_eq!(&*a, [9, 2, 3]);
+        assert_eq!(&*b, [1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rc_bytes_read_drains_front_of_view() {
+        use std::io::Read;
+
+        let mut cursor: RcBytes = RcSlice::from(Rc::from([1u8, 2, 3, 4, 5]));
+        let mut buf = [0u8; 3];
+
+        let n = cursor.read(&mut buf).unwrap();
+
+        assert_eq!(n, 3);
+        assert_eq!(buf, [1, 2, 3]);
+        assert_eq!(&*cursor, [4, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rc_bytes_fill_buf_then_consume() {
+        use std::io::BufRead;
+
+        let mut cursor: RcBytes = RcSlice::from(Rc::from([1u8, 2, 3]));
+
+        assert_eq!(cursor.fill_buf().unwrap(), [1, 2, 3]);
+        cursor.consume(2);
+        assert_eq!(&*cursor, [3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rc_bytes_independent_cursors_drain_at_different_rates() {
+        use std::io::Read;
+
+        let backing: RcBytes = RcSlice::from(Rc::from([1u8, 2, 3, 4]));
+        let mut fast = backing.clone();
+        let mut slow = backing.clone();
+
+        let mut big = [0u8; 4];
+        let mut small = [0u8; 1];
+
+        assert_eq!(fast.read(&mut big).unwrap(), 4);
+        assert_eq!(slow.read(&mut small).unwrap(), 1);
+
+        assert_eq!(big, [1, 2, 3, 4]);
+        assert_eq!(small, [1]);
+        assert_eq!(&*slow, [2, 3, 4]);
+        assert_eq!(&*fast, []);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn arc_bytes_read_drains_front_of_view() {
+        use std::io::Read;
+
+        let mut cursor: ArcBytes = ArcSlice::from(Arc::from([1u8, 2, 3, 4, 5]));
+        let mut buf = [0u8; 3];
+
+        let n = cursor.read(&mut buf).unwrap();
+
+        assert_eq!(n, 3);
+        assert_eq!(buf, [1, 2, 3]);
+        assert_eq!(&*cursor, [4, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn arc_bytes_fill_buf_then_consume() {
+        use std::io::BufRead;
+
+        let mut cursor: ArcBytes = ArcSlice::from(Arc::from([1u8, 2, 3]));
+
+        assert_eq!(cursor.fill_buf().unwrap(), [1, 2, 3]);
+        cursor.consume(2);
+        assert_eq!(&*cursor, [3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn arc_bytes_independent_cursors_drain_at_different_rates() {
+        use std::io::Read;
+
+        let backing: ArcBytes = ArcSlice::from(Arc::from([1u8, 2, 3, 4]));
+        let mut fast = backing.clone();
+        let mut slow = backing.clone();
+
+        let mut big = [0u8; 4];
+        let mut small = [0u8; 1];
+
+        assert_eq!(fast.read(&mut big).unwrap(), 4);
+        assert_eq!(slow.read(&mut small).unwrap(), 1);
+
+        assert_eq!(big, [1, 2, 3, 4]);
+        assert_eq!(small, [1]);
+        assert_eq!(&*slow, [2, 3, 4]);
+        assert_eq!(&*fast, []);
+    }
+
+    #[test]
+    fn rc_slice_from_vec_box_and_iterator() {
+        let from_vec: RcSlice<i32> = RcSlice::from(alloc::vec![1, 2, 3]);
+        let from_box: RcSlice<i32> = RcSlice::from(alloc::vec![1, 2, 3].into_boxed_slice());
+        let from_iter: RcSlice<i32> = (1..=3).collect();
+
+        assert_eq!(&*from_vec, [1, 2, 3]);
+        assert_eq!(&*from_box, [1, 2, 3]);
+        assert_eq!(&*from_iter, [1, 2, 3]);
+    }
+
+    #[test]
+    fn arc_slice_from_vec_box_and_iterator() {
+        let from_vec: ArcSlice<i32> = ArcSlice::from(alloc::vec![1, 2, 3]);
+        let from_box: ArcSlice<i32> = ArcSlice::from(alloc::vec![1, 2, 3].into_boxed_slice());
+        let from_iter: ArcSlice<i32> = (1..=3).collect();
+
+        assert_eq!(&*from_vec, [1, 2, 3]);
+        assert_eq!(&*from_box, [1, 2, 3]);
+        assert_eq!(&*from_iter, [1, 2, 3]);
+    }
+
+    #[test]
+    fn rc_slice_empty_does_not_require_clone() {
+        struct NotClone;
+
+        let e: RcSlice<NotClone> = RcSlice::empty();
+
+        assert!(e.is_empty());
+    }
+
+    #[test]
+    fn arc_slice_empty_does_not_require_clone() {
+        struct NotClone;
+
+        let e: ArcSlice<NotClone> = ArcSlice::empty();
+
+        assert!(e.is_empty());
+    }
+}